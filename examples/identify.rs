@@ -0,0 +1,37 @@
+//! Prints the identifier of an SoC connected via. any bridge backend
+//! supported by [`BridgeConfig`], which one being chosen by a config file
+//! instead of which example binary you run.
+
+use std::ffi::CStr;
+use std::fs;
+use std::path::PathBuf;
+
+use clap::Parser;
+use litex_bridge::{BridgeConfig, CsrGroup, CsrRo, SocInfo};
+
+#[derive(Parser)]
+struct Args {
+    soc_info: PathBuf,
+    bridge_config: PathBuf,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    let soc_info_json = fs::read_to_string(args.soc_info)?;
+    let soc_info: SocInfo = serde_json::from_str(&soc_info_json)?;
+
+    let bridge_config_json = fs::read_to_string(args.bridge_config)?;
+    let bridge_config: BridgeConfig = serde_json::from_str(&bridge_config_json)?;
+    let (bridge, csr_only) = bridge_config.open()?;
+
+    let addrs = CsrRo::<256>::addrs(&soc_info, csr_only, "identifier_mem")?;
+    let csr = CsrRo::<256>::backed_by(&bridge, addrs);
+    let bytes = csr
+        .read_burst()
+        .map_err(|e| anyhow::anyhow!("{e}"))?
+        .map(|x| x as u8);
+    println!("{}", CStr::from_bytes_until_nul(&bytes)?.to_str()?);
+
+    Ok(())
+}
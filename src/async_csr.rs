@@ -0,0 +1,117 @@
+//! Non-blocking CSR access for use in a Tokio event loop, instead of
+//! dedicating a thread to polling a status bit.
+//!
+//! Everything here is gated behind the `tokio` feature, and needs a
+//! multi-threaded Tokio runtime: it's built on
+//! [`block_in_place`](tokio::task::block_in_place), which panics when called
+//! from a current-thread runtime.
+
+#![cfg(feature = "tokio")]
+
+use std::time::Duration;
+
+use wishbone_bridge::BridgeError;
+
+use crate::{CsrRo, CsrRw};
+
+/// The error [`poll_until`](CsrRo::poll_until) can fail with.
+#[derive(thiserror::Error, Debug)]
+pub enum PollError {
+    // Not `#[from]`/`#[error(transparent)]`: `BridgeError` doesn't implement
+    // `std::error::Error`, just `Display`.
+    #[error("{0}")]
+    Bridge(BridgeError),
+    #[error("timed out after {0:?} waiting for the predicate to hold")]
+    Timeout(Duration),
+}
+
+impl From<BridgeError> for PollError {
+    fn from(e: BridgeError) -> Self {
+        PollError::Bridge(e)
+    }
+}
+
+impl<'a, const N: usize> CsrRo<'a, N> {
+    /// Reads this CSR the same way as [`read`](Self::read), but without
+    /// blocking the other tasks on this worker: the underlying blocking
+    /// `peek`s still run on the calling thread, via
+    /// [`block_in_place`](tokio::task::block_in_place), which first hands
+    /// those tasks off to another thread.
+    pub async fn read_async(&self) -> Result<[u32; N], BridgeError> {
+        tokio::task::block_in_place(|| self.read())
+    }
+
+    /// Repeatedly reads this CSR every `interval` until `predicate` returns
+    /// `true` for the value read, returning that value.
+    ///
+    /// Fails with [`PollError::Timeout`] if `timeout` elapses first.
+    pub async fn poll_until<F>(
+        &self,
+        interval: Duration,
+        timeout: Duration,
+        mut predicate: F,
+    ) -> Result<[u32; N], PollError>
+    where
+        F: FnMut([u32; N]) -> bool,
+    {
+        let poll = async {
+            loop {
+                let value = self.read_async().await?;
+                if predicate(value) {
+                    return Ok(value);
+                }
+                tokio::time::sleep(interval).await;
+            }
+        };
+        tokio::time::timeout(timeout, poll)
+            .await
+            .unwrap_or(Err(PollError::Timeout(timeout)))
+    }
+}
+
+impl<'a, const N: usize> CsrRw<'a, N> {
+    /// Reads this CSR the same way as [`read`](Self::read), but without
+    /// blocking the other tasks on this worker: the underlying blocking
+    /// `peek`s still run on the calling thread, via
+    /// [`block_in_place`](tokio::task::block_in_place), which first hands
+    /// those tasks off to another thread.
+    pub async fn read_async(&self) -> Result<[u32; N], BridgeError> {
+        tokio::task::block_in_place(|| self.read())
+    }
+
+    /// Writes this CSR the same way as [`write`](Self::write), but without
+    /// blocking the other tasks on this worker: the underlying blocking
+    /// `poke`s still run on the calling thread, via
+    /// [`block_in_place`](tokio::task::block_in_place), which first hands
+    /// those tasks off to another thread.
+    pub async fn write_async(&self, value: [u32; N]) -> Result<(), BridgeError> {
+        tokio::task::block_in_place(|| self.write(value))
+    }
+
+    /// Repeatedly reads this CSR every `interval` until `predicate` returns
+    /// `true` for the value read, returning that value.
+    ///
+    /// Fails with [`PollError::Timeout`] if `timeout` elapses first.
+    pub async fn poll_until<F>(
+        &self,
+        interval: Duration,
+        timeout: Duration,
+        mut predicate: F,
+    ) -> Result<[u32; N], PollError>
+    where
+        F: FnMut([u32; N]) -> bool,
+    {
+        let poll = async {
+            loop {
+                let value = self.read_async().await?;
+                if predicate(value) {
+                    return Ok(value);
+                }
+                tokio::time::sleep(interval).await;
+            }
+        };
+        tokio::time::timeout(timeout, poll)
+            .await
+            .unwrap_or(Err(PollError::Timeout(timeout)))
+    }
+}
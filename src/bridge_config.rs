@@ -0,0 +1,94 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use wishbone_bridge::{Bridge, BridgeError, EthernetBridge, PCIeBridge, SpiBridge, UartBridge, UsbBridge};
+
+/// The error [`BridgeConfig::open`] can fail with.
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    /// `SpiBridge::new`'s pin spec, as given in [`BridgeConfig::Spi`], wasn't
+    /// one of the two/three/four comma-separated pin numbers it expects.
+    #[error("invalid SPI pin spec: {0}")]
+    InvalidSpiPins(String),
+    // Not `#[from]`/`#[error(transparent)]`: `BridgeError` doesn't implement
+    // `std::error::Error`, just `Display`.
+    #[error("{0}")]
+    Bridge(BridgeError),
+}
+
+impl From<BridgeError> for Error {
+    fn from(e: BridgeError) -> Self {
+        Error::Bridge(e)
+    }
+}
+
+/// Describes which `wishbone-bridge` backend to connect through and how,
+/// so a single binary can target any of them by editing a config file
+/// instead of recompiling.
+///
+/// Deserialize one of these from whatever format you like (it just derives
+/// [`Deserialize`]) and pass it to [`open`](Self::open) to get a connected
+/// [`Bridge`], along with whether it only exposes the SoC's CSRs.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "backend", rename_all = "snake_case")]
+pub enum BridgeConfig {
+    /// Connects over Etherbone, to the SoC at `address`.
+    Ethernet { address: String },
+    /// Connects over PCIe, to the device at `device` (e.g.
+    /// `/dev/litepcie0`).
+    Pcie { device: PathBuf },
+    /// Connects over USB, optionally narrowed down to a specific `vid`/`pid`
+    /// (recommended, since otherwise it'll attempt to connect to any USB
+    /// device on the system).
+    Usb {
+        #[serde(default)]
+        vid: Option<u16>,
+        #[serde(default)]
+        pid: Option<u16>,
+    },
+    /// Connects over SPI (Raspberry Pi only), using the pins in `pins`: a
+    /// comma-separated `copi,clk`, `copi,clk,cs` or `copi,cipo,clk,cs` spec,
+    /// as taken by `SpiBridge::new`.
+    Spi { pins: String },
+    /// Connects over a UART, to the device at `device`, at `baud` baud.
+    Uart { device: PathBuf, baud: u32 },
+}
+
+impl BridgeConfig {
+    /// Builds and connects to the bridge this config describes.
+    ///
+    /// Returns the connected [`Bridge`] together with whether it only
+    /// exposes the SoC's CSRs (`csr_only`), which [`CsrGroup::addrs`] needs
+    /// to know to get the right offsets. Right now, that's only the case for
+    /// [`Pcie`](Self::Pcie): every other backend exposes the whole Wishbone
+    /// bus.
+    ///
+    /// [`CsrGroup::addrs`]: crate::CsrGroup::addrs
+    pub fn open(&self) -> Result<(Bridge, bool), Error> {
+        let (bridge, csr_only) = match self {
+            BridgeConfig::Ethernet { address } => (EthernetBridge::new(address)?.create()?, false),
+            BridgeConfig::Pcie { device } => (PCIeBridge::new(device)?.create()?, true),
+            BridgeConfig::Usb { vid, pid } => {
+                let mut usb = UsbBridge::new();
+                if let Some(vid) = vid {
+                    usb.vid(*vid);
+                }
+                if let Some(pid) = pid {
+                    usb.pid(*pid);
+                }
+                (usb.create()?, false)
+            }
+            BridgeConfig::Spi { pins } => {
+                let spi = SpiBridge::new(pins).map_err(Error::InvalidSpiPins)?;
+                (spi.create()?, false)
+            }
+            BridgeConfig::Uart { device, baud } => {
+                let mut uart = UartBridge::new(device)?;
+                uart.baud(*baud);
+                (uart.create()?, false)
+            }
+        };
+        bridge.connect()?;
+        Ok((bridge, csr_only))
+    }
+}
@@ -22,24 +22,140 @@ pub enum Error {
         expected: CsrKind,
         found: CsrKind,
     },
+    #[error("CSR `{csr}` has no field named `{field}`")]
+    MissingField { csr: String, field: String },
+}
+
+/// Reads the `index`th logical `u32` of a CSR starting at `offset`, assembling
+/// it from however many `data_width`-bit words it's split across.
+///
+/// When `data_width` is 32 this is just a single `peek`; when it's 8, the
+/// value is split across 4 word-addressed locations holding one byte each,
+/// most-significant first (see [`CsrInfo::size`](crate::CsrInfo::size)).
+fn read_word(bridge: &Bridge, offset: u32, data_width: u32, index: usize) -> Result<u32, BridgeError> {
+    if data_width == 32 {
+        return bridge.peek(offset + u32::try_from(4 * index).unwrap());
+    }
+
+    let words_per_reg = 32 / data_width;
+    let mask = (1 << data_width) - 1;
+    let base = index * usize::try_from(words_per_reg).unwrap();
+    let mut value = 0;
+    for j in 0..words_per_reg {
+        let word = bridge.peek(offset + 4 * (u32::try_from(base).unwrap() + j))?;
+        value = (value << data_width) | (word & mask);
+    }
+    Ok(value)
+}
+
+/// The inverse of [`read_word`]: splits `value` back into however many
+/// `data_width`-bit words it belongs in and `poke`s them.
+fn write_word(
+    bridge: &Bridge,
+    offset: u32,
+    data_width: u32,
+    index: usize,
+    value: u32,
+) -> Result<(), BridgeError> {
+    if data_width == 32 {
+        return bridge.poke(offset + u32::try_from(4 * index).unwrap(), value);
+    }
+
+    let words_per_reg = 32 / data_width;
+    let mask = (1 << data_width) - 1;
+    let base = index * usize::try_from(words_per_reg).unwrap();
+    for j in 0..words_per_reg {
+        let shift = data_width * (words_per_reg - 1 - j);
+        let word = (value >> shift) & mask;
+        bridge.poke(offset + 4 * (u32::try_from(base).unwrap() + j), word)?;
+    }
+    Ok(())
+}
+
+/// Extracts a bitfield spanning `size` bits starting at bit `offset` from a
+/// (possibly multi-word) CSR value, treating `value` as one contiguous
+/// little-endian bitstring with word 0 holding the least significant bits.
+fn get_field<const N: usize>(value: [u32; N], offset: u32, size: u32) -> u32 {
+    let mut result = 0;
+    for bit in 0..size {
+        let global_bit = offset + bit;
+        let word = usize::try_from(global_bit / 32).unwrap();
+        if word < N && (value[word] >> (global_bit % 32)) & 1 != 0 {
+            result |= 1 << bit;
+        }
+    }
+    result
+}
+
+/// The inverse of [`get_field`]: sets the `size`-bit field starting at bit
+/// `offset` of `value` to `field`, leaving every other bit untouched.
+fn set_field<const N: usize>(value: &mut [u32; N], offset: u32, size: u32, field: u32) {
+    for bit in 0..size {
+        let global_bit = offset + bit;
+        let word = usize::try_from(global_bit / 32).unwrap();
+        if word >= N {
+            continue;
+        }
+        let mask = 1 << (global_bit % 32);
+        if (field >> bit) & 1 != 0 {
+            value[word] |= mask;
+        } else {
+            value[word] &= !mask;
+        }
+    }
 }
 
 /// A handle to a read-only CSR in the SoC.
 pub struct CsrRo<'a, const N: usize = 1> {
     bridge: &'a Bridge,
     offset: u32,
+    data_width: u32,
 }
 
 impl<'a, const N: usize> CsrRo<'a, N> {
     pub fn read(&self) -> Result<[u32; N], BridgeError> {
         let mut result = [0; N];
-        for i in 0..N.try_into().unwrap() {
-            result[i] = self
-                .bridge
-                .peek(self.offset + u32::try_from(4 * i).unwrap())?;
+        for i in 0..N {
+            result[i] = read_word(self.bridge, self.offset, self.data_width, i)?;
         }
         Ok(result)
     }
+
+    /// Reads this CSR the same way as [`read`](Self::read), but via as few
+    /// transactions as possible.
+    ///
+    /// When `data_width` is 32, this tries `Bridge::burst_read` first, which
+    /// genuinely coalesces the read into a single USB transfer on backends
+    /// that support it. As of `wishbone-bridge` 1.1.0 that's only the USB
+    /// backend: Ethernet (Etherbone), PCIe, SPI and UART all hard-code
+    /// `ProtocolNotSupported` for it, so over those backends (and for
+    /// byte-wide CSRs) this costs exactly the same round-trips as
+    /// [`read`](Self::read) — `wishbone_bridge::Bridge` doesn't expose
+    /// anything lower-level to pack multiple words into one Etherbone record
+    /// ourselves. See [`CsrRanges`] for doing the same across a whole group
+    /// of CSRs at once, which has the same backend caveat.
+    pub fn read_burst(&self) -> Result<[u32; N], BridgeError> {
+        if self.data_width == 32 {
+            match self.bridge.burst_read(self.offset, u32::try_from(4 * N).unwrap()) {
+                Ok(bytes) => {
+                    let mut result = [0u32; N];
+                    for (word, chunk) in result.iter_mut().zip(bytes.chunks_exact(4)) {
+                        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+                    }
+                    return Ok(result);
+                }
+                Err(BridgeError::ProtocolNotSupported) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        self.read()
+    }
+
+    /// Reads a single bitfield out of this CSR, as described by a
+    /// [`CsrField`](crate::CsrField)'s `offset` and `size`.
+    pub fn field(&self, offset: u32, size: u32) -> Result<u32, BridgeError> {
+        Ok(get_field(self.read()?, offset, size))
+    }
 }
 
 impl<const N: usize> Debug for CsrRo<'_, N> {
@@ -55,26 +171,82 @@ impl<const N: usize> Debug for CsrRo<'_, N> {
 pub struct CsrRw<'a, const N: usize = 1> {
     bridge: &'a Bridge,
     offset: u32,
+    data_width: u32,
 }
 
 impl<'a, const N: usize> CsrRw<'a, N> {
     pub fn read(&self) -> Result<[u32; N], BridgeError> {
         let mut result = [0; N];
-        for i in 0..N.try_into().unwrap() {
-            result[i] = self
-                .bridge
-                .peek(self.offset + u32::try_from(4 * i).unwrap())?;
+        for i in 0..N {
+            result[i] = read_word(self.bridge, self.offset, self.data_width, i)?;
         }
         Ok(result)
     }
 
     pub fn write(&self, value: [u32; N]) -> Result<(), BridgeError> {
-        for i in 0..N.try_into().unwrap() {
-            self.bridge
-                .poke(self.offset + u32::try_from(4 * i).unwrap(), value[i])?;
+        for i in 0..N {
+            write_word(self.bridge, self.offset, self.data_width, i, value[i])?;
         }
         Ok(())
     }
+
+    /// Reads this CSR the same way as [`read`](Self::read), but via as few
+    /// transactions as possible.
+    ///
+    /// See [`CsrRo::read_burst`] for the `Bridge::burst_read` fallback
+    /// behaviour this relies on, and its caveats.
+    pub fn read_burst(&self) -> Result<[u32; N], BridgeError> {
+        if self.data_width == 32 {
+            match self.bridge.burst_read(self.offset, u32::try_from(4 * N).unwrap()) {
+                Ok(bytes) => {
+                    let mut result = [0u32; N];
+                    for (word, chunk) in result.iter_mut().zip(bytes.chunks_exact(4)) {
+                        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+                    }
+                    return Ok(result);
+                }
+                Err(BridgeError::ProtocolNotSupported) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        self.read()
+    }
+
+    /// Writes this CSR the same way as [`write`](Self::write), but via as few
+    /// transactions as possible.
+    ///
+    /// This tries `Bridge::burst_write` first and falls back to
+    /// [`write`](Self::write) on `ProtocolNotSupported`, with the same
+    /// caveats as [`CsrRo::read_burst`] has for `Bridge::burst_read`.
+    pub fn write_burst(&self, value: [u32; N]) -> Result<(), BridgeError> {
+        if self.data_width == 32 {
+            let mut bytes = Vec::with_capacity(4 * N);
+            for word in value {
+                bytes.extend_from_slice(&word.to_le_bytes());
+            }
+            match self.bridge.burst_write(self.offset, &bytes) {
+                Ok(()) => return Ok(()),
+                Err(BridgeError::ProtocolNotSupported) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        self.write(value)
+    }
+
+    /// Reads a single bitfield out of this CSR, as described by a
+    /// [`CsrField`](crate::CsrField)'s `offset` and `size`.
+    pub fn field(&self, offset: u32, size: u32) -> Result<u32, BridgeError> {
+        Ok(get_field(self.read()?, offset, size))
+    }
+
+    /// Writes a single bitfield of this CSR, as described by a
+    /// [`CsrField`](crate::CsrField)'s `offset` and `size`, leaving every
+    /// other bit as it was via a read-modify-write.
+    pub fn set_field(&self, offset: u32, size: u32, value: u32) -> Result<(), BridgeError> {
+        let mut current = self.read()?;
+        set_field(&mut current, offset, size, value);
+        self.write(current)
+    }
 }
 
 impl<const N: usize> Debug for CsrRw<'_, N> {
@@ -88,7 +260,8 @@ impl<const N: usize> Debug for CsrRw<'_, N> {
 
 /// A trait for types that are collections of CSRs.
 ///
-/// `CsrRo`, `CsrRw` and types generated by `csr_struct!` implement this.
+/// `CsrRo`, `CsrRw` and types generated by `csr_struct!` or `csr_bitfields!`
+/// implement this.
 pub trait CsrGroup<'a> {
     /// The list of CSR addresses this type needs to know before it can be
     /// constructed from a [`Bridge`].
@@ -105,8 +278,24 @@ pub trait CsrGroup<'a> {
     fn backed_by(bridge: &'a Bridge, addrs: Self::Addrs) -> Self;
 }
 
+/// The address of an individual CSR, together with the `config_csr_data_width`
+/// it needs to be accessed with.
+#[derive(Debug, Clone, Copy)]
+pub struct CsrAddr {
+    offset: u32,
+    data_width: u32,
+}
+
+/// Works out the word-addressed size a logical CSR of `n` `u32`s is expected
+/// to have, given the SoC's `config_csr_data_width`: 1 word per `u32` when
+/// it's 32, or 4 words per `u32` when it's 8.
+fn expected_size(soc_info: &SocInfo, n: usize) -> u32 {
+    let words_per_reg = 32 / soc_info.csr_data_width();
+    u32::try_from(n).unwrap() * words_per_reg
+}
+
 impl<'a, const N: usize> CsrGroup<'a> for CsrRo<'a, N> {
-    type Addrs = u32;
+    type Addrs = CsrAddr;
 
     fn addrs(soc_info: &SocInfo, csr_only: bool, module: &str) -> Result<Self::Addrs, Error> {
         // We're down to the individual CSR now, which means there's nothing left to add
@@ -118,11 +307,11 @@ impl<'a, const N: usize> CsrGroup<'a> for CsrRo<'a, N> {
                 csr: module.to_owned(),
             })?;
 
-        let expected_size = N.try_into().unwrap();
-        if info.size != expected_size {
+        let expected = expected_size(soc_info, N);
+        if info.size != expected {
             return Err(Error::CsrWrongSize {
                 csr: module.to_owned(),
-                expected: expected_size,
+                expected,
                 found: info.size,
             });
         }
@@ -138,19 +327,23 @@ impl<'a, const N: usize> CsrGroup<'a> for CsrRo<'a, N> {
         if csr_only {
             addr -= soc_info.csr_base()?;
         }
-        Ok(addr)
+        Ok(CsrAddr {
+            offset: addr,
+            data_width: soc_info.csr_data_width(),
+        })
     }
 
     fn backed_by(bridge: &'a Bridge, addrs: Self::Addrs) -> Self {
         Self {
             bridge,
-            offset: addrs,
+            offset: addrs.offset,
+            data_width: addrs.data_width,
         }
     }
 }
 
 impl<'a, const N: usize> CsrGroup<'a> for CsrRw<'a, N> {
-    type Addrs = u32;
+    type Addrs = CsrAddr;
 
     fn addrs(soc_info: &SocInfo, csr_only: bool, module: &str) -> Result<Self::Addrs, Error> {
         // We're down to the individual CSR now, which means there's nothing left to add
@@ -162,11 +355,11 @@ impl<'a, const N: usize> CsrGroup<'a> for CsrRw<'a, N> {
                 csr: module.to_owned(),
             })?;
 
-        let expected_size = N.try_into().unwrap();
-        if info.size != expected_size {
+        let expected = expected_size(soc_info, N);
+        if info.size != expected {
             return Err(Error::CsrWrongSize {
                 csr: module.to_owned(),
-                expected: expected_size,
+                expected,
                 found: info.size,
             });
         }
@@ -182,13 +375,17 @@ impl<'a, const N: usize> CsrGroup<'a> for CsrRw<'a, N> {
         if csr_only {
             addr -= soc_info.csr_base()?;
         }
-        Ok(addr)
+        Ok(CsrAddr {
+            offset: addr,
+            data_width: soc_info.csr_data_width(),
+        })
     }
 
     fn backed_by(bridge: &'a Bridge, addrs: Self::Addrs) -> Self {
         Self {
             bridge,
-            offset: addrs,
+            offset: addrs.offset,
+            data_width: addrs.data_width,
         }
     }
 }
@@ -209,6 +406,151 @@ impl<'a, T: CsrGroup<'a>> CsrGroup<'a> for Option<T> {
     }
 }
 
+/// One contiguous run of word addresses occupied by a CSR, as reported by
+/// [`CsrRanges::ranges`].
+///
+/// `words` counts `data_width`-sized slots at the usual 4-byte stride (see
+/// [`read_word`]), regardless of `data_width`: e.g. a single `u32` CSR at
+/// `data_width` 8 is 4 words wide, not 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsrRange {
+    pub offset: u32,
+    pub data_width: u32,
+    pub words: u32,
+}
+
+impl CsrRange {
+    fn new(offset: u32, data_width: u32, n: usize) -> Self {
+        let words_per_reg = 32 / data_width;
+        CsrRange {
+            offset,
+            data_width,
+            words: u32::try_from(n).unwrap() * words_per_reg,
+        }
+    }
+}
+
+/// Lists the contiguous word-address ranges a group of CSRs is backed by, so
+/// a whole [`csr_struct!`]-generated struct's worth of CSRs can be read or
+/// written via as few transactions as possible instead of one per field.
+///
+/// [`CsrRo`], [`CsrRw`], `Option<T>` and anything generated by
+/// [`csr_struct!`]/[`csr_bitfields!`] implement this alongside [`CsrGroup`].
+pub trait CsrRanges {
+    /// Every contiguous run of word addresses this group of CSRs occupies,
+    /// in the order its fields are declared, not sorted by address. Adjacent
+    /// ranges from different fields aren't merged: pass the result through
+    /// [`merge_ranges`] (which also sorts them by address) first if you want
+    /// that (you usually do, before handing it to [`burst_read_ranges`] or
+    /// [`burst_write_ranges`]).
+    fn ranges(&self) -> Vec<CsrRange>;
+}
+
+impl<const N: usize> CsrRanges for CsrRo<'_, N> {
+    fn ranges(&self) -> Vec<CsrRange> {
+        vec![CsrRange::new(self.offset, self.data_width, N)]
+    }
+}
+
+impl<const N: usize> CsrRanges for CsrRw<'_, N> {
+    fn ranges(&self) -> Vec<CsrRange> {
+        vec![CsrRange::new(self.offset, self.data_width, N)]
+    }
+}
+
+impl<T: CsrRanges> CsrRanges for Option<T> {
+    fn ranges(&self) -> Vec<CsrRange> {
+        self.as_ref().map_or_else(Vec::new, CsrRanges::ranges)
+    }
+}
+
+/// Sorts `ranges` by address and merges adjacent, same-`data_width` entries
+/// into single wider ranges, so bursting them costs one transaction per
+/// merged run instead of one per original range.
+pub fn merge_ranges(mut ranges: Vec<CsrRange>) -> Vec<CsrRange> {
+    ranges.sort_by_key(|range| range.offset);
+    let mut merged: Vec<CsrRange> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        if let Some(last) = merged.last_mut() {
+            if last.data_width == range.data_width && last.offset + 4 * last.words == range.offset {
+                last.words += range.words;
+                continue;
+            }
+        }
+        merged.push(range);
+    }
+    merged
+}
+
+/// Reads every word covered by `ranges` (typically [`merge_ranges`]'s
+/// output), using [`Bridge::burst_read`] for each range where the backend
+/// supports it and falling back to a word-by-word [`Bridge::peek`] loop
+/// otherwise, same as [`CsrRo::read_burst`].
+///
+/// Coalescing ranges together via `merge_ranges` only cuts the number of
+/// transactions on backends where `burst_read` is actually implemented
+/// (currently just USB): elsewhere this costs the same round-trips as
+/// peeking every word individually, same caveat as [`CsrRo::read_burst`].
+///
+/// The result is one raw bus word per `data_width`-sized slot, in the same
+/// order as `ranges` — *not* one already-assembled logical `u32` per CSR.
+/// For `data_width` 32 those coincide, but for byte-wide CSRs you still need
+/// the same MSB-first combining `read_word` does internally to turn 4
+/// consecutive slots back into the value [`CsrRo::read`] would've given you
+/// for that field.
+pub fn burst_read_ranges(bridge: &Bridge, ranges: &[CsrRange]) -> Result<Vec<u32>, BridgeError> {
+    let mut result = Vec::new();
+    for range in ranges {
+        if range.data_width == 32 {
+            match bridge.burst_read(range.offset, 4 * range.words) {
+                Ok(bytes) => {
+                    result.extend(
+                        bytes
+                            .chunks_exact(4)
+                            .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap())),
+                    );
+                    continue;
+                }
+                Err(BridgeError::ProtocolNotSupported) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        for i in 0..range.words {
+            result.push(bridge.peek(range.offset + 4 * i)?);
+        }
+    }
+    Ok(result)
+}
+
+/// The [`write_burst`](CsrRw::write_burst)-style counterpart to
+/// [`burst_read_ranges`]: writes `values` (one raw bus word per
+/// `data_width`-sized slot across every range in `ranges`, in the same
+/// order [`burst_read_ranges`] would've returned them in) using
+/// [`Bridge::burst_write`] where supported and falling back to a
+/// word-by-word [`Bridge::poke`] loop otherwise.
+pub fn burst_write_ranges(bridge: &Bridge, ranges: &[CsrRange], mut values: &[u32]) -> Result<(), BridgeError> {
+    for range in ranges {
+        let words = usize::try_from(range.words).unwrap();
+        let (chunk, rest) = values.split_at(words);
+        values = rest;
+        if range.data_width == 32 {
+            let mut bytes = Vec::with_capacity(4 * words);
+            for &value in chunk {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+            match bridge.burst_write(range.offset, &bytes) {
+                Ok(()) => continue,
+                Err(BridgeError::ProtocolNotSupported) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        for (i, &value) in chunk.iter().enumerate() {
+            bridge.poke(range.offset + 4 * u32::try_from(i).unwrap(), value)?;
+        }
+    }
+    Ok(())
+}
+
 /// A macro similar to `pci_driver::pci_struct` for defining groups of LiteX
 /// CSRs.
 ///
@@ -275,6 +617,185 @@ macro_rules! csr_struct {
                     .finish()
                 }
             }
+
+            impl<$lifetime> $crate::CsrRanges for $name<$lifetime> {
+                fn ranges(&self) -> $crate::std::vec::Vec<$crate::CsrRange> {
+                    let mut ranges = $crate::std::vec::Vec::new();
+                    $(ranges.extend($crate::CsrRanges::ranges(&self.$field_name()));)*
+                    ranges
+                }
+            }
+        )*
+    };
+}
+
+/// Generates a type implementing [`CsrGroup`] that gives named access to the
+/// bitfields of a single CSR, looking up each field's offset and size from
+/// [`CsrInfo::fields`](crate::CsrInfo::fields).
+///
+/// Unlike `csr_struct!`, which groups several whole CSRs together, this
+/// splits a *single* `CsrRo`/`CsrRw` into its bits. For a read-only CSR, every
+/// listed field gets a getter named after it. For a read-write CSR, list the
+/// getter and setter names separated by `/`, since stable Rust macros can't
+/// paste a `set_` prefix onto an identifier for you:
+///
+/// ```ignore
+/// csr_bitfields! {
+///     pub struct FooStatus<'a>: ro {
+///         ready,
+///         error,
+///     }
+/// }
+///
+/// csr_bitfields! {
+///     pub struct FooCtrl<'a>: rw {
+///         enable / set_enable,
+///     }
+/// }
+/// ```
+///
+/// A `ro` block and an `rw` block can't be mixed in the same `csr_bitfields!`
+/// call; use two calls like above.
+#[macro_export]
+macro_rules! csr_bitfields {
+    (
+        $(
+            $(#[$attr:meta])*
+            $vis:vis struct $name:ident<$lifetime:lifetime>: ro {
+                $(
+                    $(#[$field_attr:meta])*
+                    $field_vis:vis $field_name:ident
+                ),* $(,)?
+            }
+        )*
+    ) => {
+        $(
+            $(#[$attr])*
+            #[derive($crate::std::clone::Clone, $crate::std::marker::Copy)]
+            $vis struct $name<$lifetime, T = &$lifetime $crate::wishbone_bridge::Bridge> {
+                bridge: T,
+                reg: <$crate::CsrRo<$lifetime> as $crate::CsrGroup<$lifetime>>::Addrs,
+                $($field_name: ($crate::std::primitive::u32, $crate::std::primitive::u32),)*
+            }
+
+            impl<$lifetime> $crate::CsrGroup<$lifetime> for $name<$lifetime> {
+                type Addrs = $name<$lifetime, ()>;
+
+                fn addrs(soc_info: &$crate::SocInfo, csr_only: $crate::std::primitive::bool, module: &$crate::std::primitive::str) -> $crate::std::result::Result<Self::Addrs, $crate::Error> {
+                    Ok($name {
+                        bridge: (),
+                        reg: <$crate::CsrRo<$lifetime> as $crate::CsrGroup<$lifetime>>::addrs(soc_info, csr_only, module)?,
+                        $($field_name: soc_info.csr_field(module, $crate::std::stringify!($field_name))?,)*
+                    })
+                }
+
+                fn backed_by(bridge: &$lifetime $crate::wishbone_bridge::Bridge, addrs: Self::Addrs) -> Self {
+                    Self {
+                        bridge,
+                        reg: addrs.reg,
+                        $($field_name: addrs.$field_name,)*
+                    }
+                }
+            }
+
+            impl<$lifetime> $name<$lifetime> {
+                $(
+                    $(#[$field_attr])*
+                    $field_vis fn $field_name(&self) -> $crate::std::result::Result<$crate::std::primitive::u32, $crate::wishbone_bridge::BridgeError> {
+                        let (offset, size) = self.$field_name;
+                        <$crate::CsrRo<$lifetime> as $crate::CsrGroup<$lifetime>>::backed_by(self.bridge, self.reg).field(offset, size)
+                    }
+                )*
+            }
+
+            impl<$lifetime> $crate::std::fmt::Debug for $name<$lifetime> {
+                fn fmt(&self, f: &mut $crate::std::fmt::Formatter<'_>) -> $crate::std::fmt::Result {
+                    f.debug_struct($crate::std::stringify!($name))
+                    $(
+                        .field($crate::std::stringify!($field_name), &self.$field_name())
+                    )*
+                    .finish()
+                }
+            }
+
+            impl<$lifetime> $crate::CsrRanges for $name<$lifetime> {
+                fn ranges(&self) -> $crate::std::vec::Vec<$crate::CsrRange> {
+                    $crate::CsrRanges::ranges(&<$crate::CsrRo<$lifetime> as $crate::CsrGroup<$lifetime>>::backed_by(self.bridge, self.reg))
+                }
+            }
+        )*
+    };
+    (
+        $(
+            $(#[$attr:meta])*
+            $vis:vis struct $name:ident<$lifetime:lifetime>: rw {
+                $(
+                    $(#[$field_attr:meta])*
+                    $field_vis:vis $field_name:ident / $setter_vis:vis $setter_name:ident
+                ),* $(,)?
+            }
+        )*
+    ) => {
+        $(
+            $(#[$attr])*
+            #[derive($crate::std::clone::Clone, $crate::std::marker::Copy)]
+            $vis struct $name<$lifetime, T = &$lifetime $crate::wishbone_bridge::Bridge> {
+                bridge: T,
+                reg: <$crate::CsrRw<$lifetime> as $crate::CsrGroup<$lifetime>>::Addrs,
+                $($field_name: ($crate::std::primitive::u32, $crate::std::primitive::u32),)*
+            }
+
+            impl<$lifetime> $crate::CsrGroup<$lifetime> for $name<$lifetime> {
+                type Addrs = $name<$lifetime, ()>;
+
+                fn addrs(soc_info: &$crate::SocInfo, csr_only: $crate::std::primitive::bool, module: &$crate::std::primitive::str) -> $crate::std::result::Result<Self::Addrs, $crate::Error> {
+                    Ok($name {
+                        bridge: (),
+                        reg: <$crate::CsrRw<$lifetime> as $crate::CsrGroup<$lifetime>>::addrs(soc_info, csr_only, module)?,
+                        $($field_name: soc_info.csr_field(module, $crate::std::stringify!($field_name))?,)*
+                    })
+                }
+
+                fn backed_by(bridge: &$lifetime $crate::wishbone_bridge::Bridge, addrs: Self::Addrs) -> Self {
+                    Self {
+                        bridge,
+                        reg: addrs.reg,
+                        $($field_name: addrs.$field_name,)*
+                    }
+                }
+            }
+
+            impl<$lifetime> $name<$lifetime> {
+                $(
+                    $(#[$field_attr])*
+                    $field_vis fn $field_name(&self) -> $crate::std::result::Result<$crate::std::primitive::u32, $crate::wishbone_bridge::BridgeError> {
+                        let (offset, size) = self.$field_name;
+                        <$crate::CsrRw<$lifetime> as $crate::CsrGroup<$lifetime>>::backed_by(self.bridge, self.reg).field(offset, size)
+                    }
+
+                    $(#[$field_attr])*
+                    $setter_vis fn $setter_name(&self, value: $crate::std::primitive::u32) -> $crate::std::result::Result<(), $crate::wishbone_bridge::BridgeError> {
+                        let (offset, size) = self.$field_name;
+                        <$crate::CsrRw<$lifetime> as $crate::CsrGroup<$lifetime>>::backed_by(self.bridge, self.reg).set_field(offset, size, value)
+                    }
+                )*
+            }
+
+            impl<$lifetime> $crate::std::fmt::Debug for $name<$lifetime> {
+                fn fmt(&self, f: &mut $crate::std::fmt::Formatter<'_>) -> $crate::std::fmt::Result {
+                    f.debug_struct($crate::std::stringify!($name))
+                    $(
+                        .field($crate::std::stringify!($field_name), &self.$field_name())
+                    )*
+                    .finish()
+                }
+            }
+
+            impl<$lifetime> $crate::CsrRanges for $name<$lifetime> {
+                fn ranges(&self) -> $crate::std::vec::Vec<$crate::CsrRange> {
+                    $crate::CsrRanges::ranges(&<$crate::CsrRw<$lifetime> as $crate::CsrGroup<$lifetime>>::backed_by(self.bridge, self.reg))
+                }
+            }
         )*
     };
 }
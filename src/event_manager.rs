@@ -0,0 +1,77 @@
+use wishbone_bridge::{Bridge, BridgeError};
+
+use crate::{CsrGroup, CsrRo, CsrRw, Error, SocInfo};
+
+/// A handle to the conventional trio of CSRs LiteX generates for a module
+/// that can raise interrupts: `<module>_ev_status`, `<module>_ev_pending` and
+/// `<module>_ev_enable`.
+///
+/// Each of those CSRs is a bitmask with one bit per event the module can
+/// raise. To find out what those bits mean, pair this with
+/// [`csr_bitfields!`](crate::csr_bitfields) on the `ev_status`/`ev_pending`
+/// CSR using the field names LiteX exports, rather than working with the raw
+/// `u32` masks here.
+pub struct EventManager<'a> {
+    status: CsrRo<'a>,
+    pending: CsrRw<'a>,
+    enable: CsrRw<'a>,
+}
+
+impl<'a> CsrGroup<'a> for EventManager<'a> {
+    type Addrs = (
+        <CsrRo<'a> as CsrGroup<'a>>::Addrs,
+        <CsrRw<'a> as CsrGroup<'a>>::Addrs,
+        <CsrRw<'a> as CsrGroup<'a>>::Addrs,
+    );
+
+    fn addrs(soc_info: &SocInfo, csr_only: bool, module: &str) -> Result<Self::Addrs, Error> {
+        Ok((
+            CsrRo::<1>::addrs(soc_info, csr_only, &format!("{module}_ev_status"))?,
+            CsrRw::<1>::addrs(soc_info, csr_only, &format!("{module}_ev_pending"))?,
+            CsrRw::<1>::addrs(soc_info, csr_only, &format!("{module}_ev_enable"))?,
+        ))
+    }
+
+    fn backed_by(bridge: &'a Bridge, addrs: Self::Addrs) -> Self {
+        let (status, pending, enable) = addrs;
+        Self {
+            status: CsrRo::backed_by(bridge, status),
+            pending: CsrRw::backed_by(bridge, pending),
+            enable: CsrRw::backed_by(bridge, enable),
+        }
+    }
+}
+
+impl<'a> EventManager<'a> {
+    /// Reads which events are currently active, regardless of whether
+    /// they're enabled: `ev_status`.
+    pub fn status(&self) -> Result<u32, BridgeError> {
+        self.status.read().map(|[status]| status)
+    }
+
+    /// Reads which events are pending: active, enabled, and not yet
+    /// acknowledged: `ev_pending`.
+    pub fn pending(&self) -> Result<u32, BridgeError> {
+        self.pending.read().map(|[pending]| pending)
+    }
+
+    /// Acknowledges `events`, clearing their bits out of
+    /// [`pending`](Self::pending).
+    ///
+    /// `ev_pending` is write-1-to-clear, so bits left unset in `events` are
+    /// left alone.
+    pub fn clear(&self, events: u32) -> Result<(), BridgeError> {
+        self.pending.write([events])
+    }
+
+    /// Reads which events are currently enabled: `ev_enable`.
+    pub fn enabled(&self) -> Result<u32, BridgeError> {
+        self.enable.read().map(|[enable]| enable)
+    }
+
+    /// Sets which events are enabled, replacing whatever was there before:
+    /// writes `ev_enable`.
+    pub fn set_enabled(&self, events: u32) -> Result<(), BridgeError> {
+        self.enable.write([events])
+    }
+}
@@ -0,0 +1,169 @@
+use wishbone_bridge::{Bridge, BridgeError};
+
+use crate::SocInfo;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("no memory region named `{region}` found in SocInfo")]
+    MissingRegion { region: String },
+    #[error(
+        "access to `{region}` at offset {offset} with length {len} is out of bounds (region is {size} bytes)"
+    )]
+    OutOfBounds {
+        region: String,
+        offset: u32,
+        len: u32,
+        size: u32,
+    },
+    // Not `#[from]`/`#[error(transparent)]`: `BridgeError` doesn't implement
+    // `std::error::Error`, just `Display`.
+    #[error("{0}")]
+    Bridge(BridgeError),
+    #[error(transparent)]
+    Csr(#[from] crate::Error),
+}
+
+impl From<BridgeError> for Error {
+    fn from(e: BridgeError) -> Self {
+        Error::Bridge(e)
+    }
+}
+
+/// A handle to a region of the SoC's memory, e.g. `main_ram` or `sram`, as
+/// described by [`SocInfo::memories`].
+///
+/// Unlike [`CsrRo`](crate::CsrRo)/[`CsrRw`](crate::CsrRw), this isn't a
+/// [`CsrGroup`](crate::CsrGroup): a memory region isn't made up of individual
+/// CSRs, so there's nothing to look up besides the region itself.
+pub struct Memory<'a> {
+    bridge: &'a Bridge,
+    name: String,
+    base: u32,
+    size: u32,
+}
+
+impl<'a> Memory<'a> {
+    /// Creates a handle to the memory region called `name` in `soc_info`.
+    ///
+    /// Same as [`CsrGroup::addrs`](crate::CsrGroup::addrs), `csr_only` needs
+    /// to be set if `bridge` only exposes the SoC's CSRs (right now, just the
+    /// PCIe bridge), so that the region's base address gets the CSR base
+    /// subtracted from it to match.
+    pub fn backed_by(
+        bridge: &'a Bridge,
+        soc_info: &SocInfo,
+        csr_only: bool,
+        name: &str,
+    ) -> Result<Self, Error> {
+        let region = soc_info
+            .memories
+            .get(name)
+            .ok_or_else(|| Error::MissingRegion {
+                region: name.to_owned(),
+            })?;
+
+        let mut base = region.base;
+        if csr_only {
+            base -= soc_info.csr_base()?;
+        }
+
+        Ok(Self {
+            bridge,
+            name: name.to_owned(),
+            base,
+            size: region.size,
+        })
+    }
+
+    /// The size of this region, in bytes.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    fn check_bounds(&self, offset: u32, len: u32) -> Result<(), Error> {
+        let in_bounds = offset
+            .checked_add(len)
+            .is_some_and(|end| end <= self.size);
+        if !in_bounds {
+            return Err(Error::OutOfBounds {
+                region: self.name.clone(),
+                offset,
+                len,
+                size: self.size,
+            });
+        }
+        Ok(())
+    }
+
+    /// Reads `len` bytes starting at `offset` bytes into this region.
+    ///
+    /// `offset` and `len` don't need to be word-aligned: unaligned reads are
+    /// handled by reading the words that cover the requested range and
+    /// slicing out the bytes that were asked for. Words are assumed to store
+    /// their bytes little-endian, matching the SoCs this crate has been used
+    /// with so far.
+    pub fn read_bytes(&self, offset: u32, len: u32) -> Result<Vec<u8>, Error> {
+        self.check_bounds(offset, len)?;
+
+        let start_word = offset / 4;
+        let end_word = (offset + len).div_ceil(4);
+
+        let mut bytes = Vec::with_capacity(((end_word - start_word) * 4) as usize);
+        for word in start_word..end_word {
+            let value = self.bridge.peek(self.base + word * 4)?;
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        let skip = (offset % 4) as usize;
+        bytes.drain(..skip);
+        bytes.truncate(len as usize);
+        Ok(bytes)
+    }
+
+    /// Writes `data` starting at `offset` bytes into this region.
+    ///
+    /// Like [`read_bytes`](Self::read_bytes), `offset` and `data.len()` don't
+    /// need to be word-aligned: any partial word at either end of the range
+    /// is read first so the bytes outside `data` aren't clobbered.
+    pub fn write_bytes(&self, offset: u32, data: &[u8]) -> Result<(), Error> {
+        let len = u32::try_from(data.len()).unwrap();
+        self.check_bounds(offset, len)?;
+
+        let start_word = offset / 4;
+        let end_word = (offset + len).div_ceil(4);
+        let mut buf = vec![0u8; ((end_word - start_word) * 4) as usize];
+
+        let lead = (offset % 4) as usize;
+        let trail = buf.len() - lead - data.len();
+        if lead != 0 {
+            let word = self.bridge.peek(self.base + start_word * 4)?;
+            buf[..4].copy_from_slice(&word.to_le_bytes());
+        }
+        if trail != 0 {
+            let word = self.bridge.peek(self.base + (end_word - 1) * 4)?;
+            let n = buf.len();
+            buf[n - 4..].copy_from_slice(&word.to_le_bytes());
+        }
+        buf[lead..lead + data.len()].copy_from_slice(data);
+
+        for (i, chunk) in buf.chunks_exact(4).enumerate() {
+            let word = u32::from_le_bytes(chunk.try_into().unwrap());
+            let addr = self.base + (start_word + u32::try_from(i).unwrap()) * 4;
+            self.bridge.poke(addr, word)?;
+        }
+        Ok(())
+    }
+
+    /// Reads the `u32` at `offset` bytes into this region.
+    pub fn read_u32(&self, offset: u32) -> Result<u32, Error> {
+        self.check_bounds(offset, 4)?;
+        Ok(self.bridge.peek(self.base + offset)?)
+    }
+
+    /// Writes the `u32` at `offset` bytes into this region.
+    pub fn write_u32(&self, offset: u32, value: u32) -> Result<(), Error> {
+        self.check_bounds(offset, 4)?;
+        self.bridge.poke(self.base + offset, value)?;
+        Ok(())
+    }
+}
@@ -44,22 +44,44 @@ pub struct SocInfo {
 }
 
 /// Information about an individual CSR.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CsrInfo {
     /// The address of the register.
     ///
     /// Same as [`SocInfo::csr_bases`], these are addresses on the SoC's
     /// main bus.
     pub addr: u32,
-    /// The number of `u32`s this CSR takes up.
+    /// The number of word-addressed locations this CSR takes up.
     ///
-    /// TODO: this is actually in units of `config_csr_data_width` (one of the
-    /// values in [`SocInfo::constants`]), support that being 8 bits (the only
-    /// other valid value).
+    /// When `config_csr_data_width` (one of the values in
+    /// [`SocInfo::constants`]) is 32, this is also the number of `u32`s the
+    /// CSR is made up of. When it's 8 (the only other valid value), each of
+    /// these locations only holds a single byte in its low 8 bits, so a
+    /// logical `u32` is spread across 4 of them, most-significant byte first;
+    /// see [`SocInfo::csr_data_width`].
     pub size: u32,
     /// Whether the CSR is read-only or read-write.
     #[serde(rename = "type")]
     pub kind: CsrKind,
+    /// The bitfields this CSR is split into, if LiteX was told about them
+    /// (e.g. by passing `fields=[...]` to `CSRStatus`/`CSRStorage` in
+    /// Python).
+    ///
+    /// Empty if the SoC JSON didn't describe any, which is the common case.
+    #[serde(default)]
+    pub fields: Vec<CsrField>,
+}
+
+/// A single named bitfield within a CSR, as described by [`CsrInfo::fields`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CsrField {
+    /// The name of the field, e.g. `"ready"`.
+    pub name: String,
+    /// The bit offset of the field within the CSR, counting from the least
+    /// significant bit.
+    pub offset: u32,
+    /// The number of bits the field occupies.
+    pub size: u32,
 }
 
 /// Whether a CSR is read-only or read-write.
@@ -125,4 +147,37 @@ impl SocInfo {
             None => Err(crate::csr::Error::NoCsrRegion),
         }
     }
+
+    /// The width, in bits, of the locations CSRs are split across:
+    /// `config_csr_data_width` in [`constants`](Self::constants).
+    ///
+    /// Defaults to 32 if the constant isn't present, since that's the default
+    /// LiteX uses too.
+    pub fn csr_data_width(&self) -> u32 {
+        match self.constants.get("config_csr_data_width") {
+            Some(Some(SocConstant::Integer(width))) => u32::try_from(*width).unwrap(),
+            _ => 32,
+        }
+    }
+
+    /// Looks up the bit offset and size of a named field of the CSR called
+    /// `csr`.
+    pub fn csr_field(&self, csr: &str, field: &str) -> Result<(u32, u32), crate::Error> {
+        let info = self
+            .csr_registers
+            .get(csr)
+            .ok_or_else(|| crate::csr::Error::MissingCsr {
+                csr: csr.to_owned(),
+            })?;
+        info.fields
+            .iter()
+            .find(|f| f.name == field)
+            .map(|f| (f.offset, f.size))
+            .ok_or_else(|| {
+                crate::csr::Error::MissingField {
+                    csr: csr.to_owned(),
+                    field: field.to_owned(),
+                }
+            })
+    }
 }